@@ -23,3 +23,8 @@ impl Archetype {
     }
 }
 
+impl Default for Archetype {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+// One end of a registered clamp: a fixed number or another attribute's
+// current value, so health can be capped by max_health as it changes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bound {
+    Value(f32),
+    Attribute(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AttributesComponent {
+    values: HashMap<String, f32>,
+    clamps: HashMap<String, (Bound, Bound)>,
+}
+
+impl AttributesComponent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<f32> {
+        self.values.get(key).copied()
+    }
+
+    pub fn get_clamped(&self, key: &str, min: f32, max: f32) -> Option<f32> {
+        self.get(key).map(|value| value.max(min).min(max))
+    }
+
+    // Stores the value after applying any clamp registered for the key.
+    pub fn set(&mut self, key: &str, value: f32) -> f32 {
+        let value = self.clamp(key, value);
+        self.values.insert(key.to_string(), value);
+        value
+    }
+
+    // Adds delta to the current value (0 if unset) and returns the result.
+    pub fn modify(&mut self, key: &str, delta: f32) -> f32 {
+        let current = self.get(key).unwrap_or(0.0);
+        self.set(key, current + delta)
+    }
+
+    // The clamp applies to later writes and to the value already stored.
+    // A bound naming an attribute that is not set is ignored.
+    pub fn register_clamp(&mut self, key: &str, min: Bound, max: Bound) {
+        self.clamps.insert(key.to_string(), (min, max));
+        if let Some(value) = self.get(key) {
+            self.set(key, value);
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    fn clamp(&self, key: &str, value: f32) -> f32 {
+        let Some((min, max)) = self.clamps.get(key) else {
+            return value;
+        };
+        let mut value = value;
+        if let Some(min) = self.resolve(min) {
+            value = value.max(min);
+        }
+        if let Some(max) = self.resolve(max) {
+            value = value.min(max);
+        }
+        value
+    }
+
+    fn resolve(&self, bound: &Bound) -> Option<f32> {
+        match bound {
+            Bound::Value(value) => Some(*value),
+            Bound::Attribute(key) => self.get(key),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct LifetimeComponent {
+    pub remaining: f32,
+}
@@ -1,6 +1,12 @@
 pub mod position;
 pub mod name;
+pub mod lifetime;
+pub mod patrol;
+pub mod attributes;
 
 pub use position::Position;
 pub use name::Name;
+pub use lifetime::LifetimeComponent;
+pub use patrol::PatrolComponent;
+pub use attributes::{AttributesComponent, Bound};
 
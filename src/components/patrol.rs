@@ -0,0 +1,24 @@
+use crate::components::Position;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatrolComponent {
+    pub waypoints: Vec<Position>,
+    pub speed: f32,
+    pub looping: bool,
+    pub next_waypoint: usize,
+}
+
+impl PatrolComponent {
+    pub fn new(waypoints: Vec<Position>, speed: f32, looping: bool) -> Self {
+        Self {
+            waypoints,
+            speed,
+            looping,
+            next_waypoint: 0,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_waypoint >= self.waypoints.len()
+    }
+}
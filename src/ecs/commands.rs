@@ -0,0 +1,45 @@
+use crate::components::{Position, Name};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EcsCommand {
+    Spawn { position: Position, name: Name },
+    SpawnSilent { position: Position, name: Name },
+    Despawn { id: u32 },
+}
+
+// Structural changes queued while systems iterate archetypes, applied in
+// order by `ECS::apply_commands` once iteration is done.
+#[derive(Debug, Default)]
+pub struct EcsCommands {
+    commands: Vec<EcsCommand>,
+}
+
+impl EcsCommands {
+    pub fn new() -> Self {
+        Self { commands: Vec::new() }
+    }
+
+    pub fn spawn(&mut self, position: Position, name: Name) {
+        self.commands.push(EcsCommand::Spawn { position, name });
+    }
+
+    pub fn spawn_silent(&mut self, position: Position, name: Name) {
+        self.commands.push(EcsCommand::SpawnSilent { position, name });
+    }
+
+    pub fn despawn(&mut self, id: u32) {
+        self.commands.push(EcsCommand::Despawn { id });
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub fn drain(&mut self) -> std::vec::Drain<'_, EcsCommand> {
+        self.commands.drain(..)
+    }
+}
@@ -1,6 +1,9 @@
 use crate::archetypes::Archetype;
 use crate::components::{Position, Name};
+use crate::ecs::commands::{EcsCommand, EcsCommands};
 use crate::ecs::entity_manager::EntityManager;
+use crate::ecs::events::{EntityEvent, EntityEvents};
+use crate::ecs::query::{Query, QueryMut};
 use crate::ecs::tag_manager::TagManager;
 use std::collections::HashMap;
 use log::debug;
@@ -10,6 +13,7 @@ pub struct ECS {
     pub entity_to_location: HashMap<u32, (usize, usize)>,
     pub entity_manager: EntityManager,
     pub tag_manager: TagManager,
+    pub events: EntityEvents,
 }
 
 impl ECS {
@@ -19,6 +23,7 @@ impl ECS {
             entity_to_location: HashMap::new(),
             entity_manager: EntityManager::new(),
             tag_manager: TagManager::new(), 
+            events: EntityEvents::new(),
         }
     }
 
@@ -26,7 +31,29 @@ impl ECS {
         &mut self,
         position: Position,
         name: Name,
-    ) {
+    ) -> u32 {
+        let id = self.spawn(position, name);
+        self.events.send(EntityEvent::Spawned { id });
+        id
+    }
+
+    // Skips the Spawned event for high-churn entities. Despawns still emit.
+    pub fn add_entity_silent(
+        &mut self,
+        position: Position,
+        name: Name,
+    ) -> u32 {
+        self.spawn(position, name)
+    }
+
+    // None once the entity is removed; a recycled id gets a new generation.
+    pub fn generation(&self, id: u32) -> Option<u32> {
+        self.entity_to_location
+            .contains_key(&id)
+            .then(|| self.entity_manager.generation(id))
+    }
+
+    fn spawn(&mut self, position: Position, name: Name) -> u32 {
         let id = self.entity_manager.create_entity();
         if self.archetypes.is_empty() {
             self.archetypes.push(Archetype::new());
@@ -39,6 +66,7 @@ impl ECS {
         archetype.add_entity(id, position,name);
         self.entity_to_location.insert(id, (archetype_index, index_within_archetype));
         debug!("Entity {} created. Current entity count: {}", id, self.entity_to_location.len()); 
+        id
     }
 
     pub fn find_entity(&self, id: u32) -> Option<&Archetype> {
@@ -64,6 +92,80 @@ impl ECS {
         }
     }
 
+    pub fn query<'a, Q: Query + 'a>(&'a self) -> impl Iterator<Item = (u32, Q::Item<'a>)> + 'a {
+        self.archetypes.iter().flat_map(|archetype| {
+            archetype
+                .entity_ids
+                .iter()
+                .enumerate()
+                .map(move |(index, &id)| (id, Q::fetch(archetype, index)))
+        })
+    }
+
+    pub fn query_mut<'a, Q: QueryMut + 'a>(&'a mut self) -> impl Iterator<Item = (u32, Q::Item<'a>)> + 'a {
+        self.archetypes.iter_mut().flat_map(|archetype| Q::fetch_mut(archetype))
+    }
+
+    pub fn tagged_with_positions<'a>(&'a self, tag: &str) -> impl Iterator<Item = (u32, &'a Position)> + 'a {
+        self.tag_manager
+            .get_entities_with_tag(tag)
+            .into_iter()
+            .flatten()
+            .filter_map(move |&id| {
+                let &(archetype_index, index_within_archetype) = self.entity_to_location.get(&id)?;
+                Some((id, &self.archetypes[archetype_index].positions[index_within_archetype]))
+            })
+    }
+
+    pub fn tagged_with_positions_mut<'a>(&'a mut self, tag: &str) -> impl Iterator<Item = (u32, &'a mut Position)> + 'a {
+        let mut locations: Vec<(usize, usize, u32)> = self
+            .tag_manager
+            .get_entities_with_tag(tag)
+            .into_iter()
+            .flatten()
+            .filter_map(|&id| {
+                let &(archetype_index, index_within_archetype) = self.entity_to_location.get(&id)?;
+                Some((archetype_index, index_within_archetype, id))
+            })
+            .collect();
+        locations.sort_unstable();
+
+        // Walk the sorted locations, splitting each column so every
+        // position is borrowed exactly once
+        let mut positions = Vec::with_capacity(locations.len());
+        let mut pending = locations.into_iter().peekable();
+        for (archetype_index, archetype) in self.archetypes.iter_mut().enumerate() {
+            if pending.peek().is_none() {
+                break;
+            }
+            let mut rest: &mut [Position] = &mut archetype.positions;
+            let mut offset = 0;
+            while let Some(&(_, index_within_archetype, id)) = pending.peek().filter(|location| location.0 == archetype_index) {
+                pending.next();
+                let (_, tail) = std::mem::take(&mut rest).split_at_mut(index_within_archetype - offset);
+                let (position, tail) = tail.split_first_mut().expect("entity location out of bounds");
+                positions.push((id, position));
+                rest = tail;
+                offset = index_within_archetype + 1;
+            }
+        }
+        positions.into_iter()
+    }
+
+    pub fn apply_commands(&mut self, commands: &mut EcsCommands) {
+        for command in commands.drain() {
+            match command {
+                EcsCommand::Spawn { position, name } => {
+                    self.add_entity(position, name);
+                }
+                EcsCommand::SpawnSilent { position, name } => {
+                    self.add_entity_silent(position, name);
+                }
+                EcsCommand::Despawn { id } => self.remove_entity(id),
+            }
+        }
+    }
+
     pub fn remove_entity(&mut self, id:u32) {
                 if let Some((archetype_index, index_within_archetype)) = self.entity_to_location.remove(&id) {
             let archetype = &mut self.archetypes[archetype_index];
@@ -72,11 +174,22 @@ impl ECS {
             archetype.entity_ids.swap_remove(index_within_archetype);
             archetype.positions.swap_remove(index_within_archetype);
             archetype.names.swap_remove(index_within_archetype);
+            // The last entity was swapped into the freed slot
+            if let Some(&moved_id) = archetype.entity_ids.get(index_within_archetype) {
+                self.entity_to_location.insert(moved_id, (archetype_index, index_within_archetype));
+            }
+            self.tag_manager.remove_entity(id);
             // Recycle the ID
             self.entity_manager.destroy_entity(id);
+            self.events.send(EntityEvent::Despawned { id });
             debug!("Entity {} deleted. Current entity count: {}", id, self.entity_to_location.len());
         }
     }
+}
 
+impl Default for ECS {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
@@ -3,6 +3,9 @@ use std::collections::HashSet;
 pub struct EntityManager {
     pub next_entity_id: u32,
     pub recycled_ids: HashSet<u32>,
+    // Bumped each time an id is destroyed, so a recycled id can be told
+    // apart from the entity that held it before.
+    pub generations: Vec<u32>,
 }
 
 impl EntityManager {
@@ -10,6 +13,7 @@ impl EntityManager {
         Self {
             next_entity_id: 0,
             recycled_ids: HashSet::new(),
+            generations: Vec::new(),
         }
     }
 
@@ -20,12 +24,25 @@ impl EntityManager {
         } else {
             let id = self.next_entity_id;
             self.next_entity_id += 1;
+            self.generations.push(0);
             id
         }
     }
 
     pub fn destroy_entity(&mut self, id: u32) {
+        if let Some(generation) = self.generations.get_mut(id as usize) {
+            *generation = generation.wrapping_add(1);
+        }
         self.recycled_ids.insert(id);
     }
+
+    pub fn generation(&self, id: u32) -> u32 {
+        self.generations.get(id as usize).copied().unwrap_or(0)
+    }
 }
 
+impl Default for EntityManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,77 @@
+use crate::ecs::ECS;
+use std::collections::HashMap;
+
+// Per-entity data that has no archetype column. Each entry remembers the
+// generation of the entity it was inserted for, so it never carries over
+// to a later entity that recycles the id, whenever the despawn happened.
+#[derive(Debug)]
+pub struct EntityTable<T> {
+    entries: HashMap<u32, (u32, T)>,
+}
+
+impl<T> EntityTable<T> {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    // Returns false, storing nothing, if the entity is not alive.
+    pub fn insert(&mut self, ecs: &ECS, entity: u32, value: T) -> bool {
+        match ecs.generation(entity) {
+            Some(generation) => {
+                self.entries.insert(entity, (generation, value));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get(&self, ecs: &ECS, entity: u32) -> Option<&T> {
+        let (generation, value) = self.entries.get(&entity)?;
+        (ecs.generation(entity) == Some(*generation)).then_some(value)
+    }
+
+    pub fn get_mut(&mut self, ecs: &ECS, entity: u32) -> Option<&mut T> {
+        let (generation, value) = self.entries.get_mut(&entity)?;
+        (ecs.generation(entity) == Some(*generation)).then_some(value)
+    }
+
+    pub fn remove(&mut self, entity: u32) -> Option<T> {
+        self.entries.remove(&entity).map(|(_, value)| value)
+    }
+
+    // Drops entries whose entity has been removed, including ids that
+    // have since been handed to a new entity.
+    pub fn prune(&mut self, ecs: &ECS) {
+        self.entries
+            .retain(|&entity, (generation, _)| ecs.generation(entity) == Some(*generation));
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(u32, &mut T) -> bool) {
+        self.entries.retain(|&entity, (_, value)| keep(entity, value));
+    }
+
+    // Iteration does not check liveness; call prune first.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &T)> {
+        self.entries.iter().map(|(&entity, (_, value))| (entity, value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (u32, &mut T)> {
+        self.entries.iter_mut().map(|(&entity, (_, value))| (entity, value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T> Default for EntityTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
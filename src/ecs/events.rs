@@ -0,0 +1,67 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityEvent {
+    Spawned { id: u32 },
+    Despawned { id: u32 },
+}
+
+// Double-buffered so events live for two `update`s: long enough for every
+// reader that runs once per frame to see them, without growing forever.
+#[derive(Debug, Default)]
+pub struct EntityEvents {
+    previous: Vec<EntityEvent>,
+    current: Vec<EntityEvent>,
+    // Total events ever sent; readers compare their cursor against this
+    event_count: usize,
+}
+
+impl EntityEvents {
+    pub fn new() -> Self {
+        Self {
+            previous: Vec::new(),
+            current: Vec::new(),
+            event_count: 0,
+        }
+    }
+
+    pub fn send(&mut self, event: EntityEvent) {
+        self.current.push(event);
+        self.event_count += 1;
+    }
+
+    // Call once per frame; drops events sent before the previous update.
+    pub fn update(&mut self) {
+        self.previous = std::mem::take(&mut self.current);
+    }
+
+    pub fn len(&self) -> usize {
+        self.previous.len() + self.current.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn oldest_index(&self) -> usize {
+        self.event_count - self.len()
+    }
+}
+
+// Each consumer keeps its own reader, so one system reading events does not
+// hide them from another.
+#[derive(Debug, Default)]
+pub struct EventReader {
+    last_read: usize,
+}
+
+impl EventReader {
+    pub fn new() -> Self {
+        Self { last_read: 0 }
+    }
+
+    pub fn read<'a>(&mut self, events: &'a EntityEvents) -> impl Iterator<Item = &'a EntityEvent> + 'a {
+        // Events already dropped by `update` are skipped
+        let skip = self.last_read.saturating_sub(events.oldest_index());
+        self.last_read = events.event_count;
+        events.previous.iter().chain(events.current.iter()).skip(skip)
+    }
+}
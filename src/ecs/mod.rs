@@ -1,5 +1,14 @@
+#[allow(clippy::module_inception)]
 pub mod ecs;
+pub mod commands;
 pub mod entity_manager;
+pub mod entity_table;
+pub mod events;
+pub mod query;
 pub mod tag_manager;
 
 pub use ecs::ECS;
+pub use commands::EcsCommands;
+pub use entity_table::EntityTable;
+pub use events::{EntityEvent, EntityEvents, EventReader};
+pub use query::{Query, QueryMut};
@@ -0,0 +1,118 @@
+use crate::archetypes::Archetype;
+use crate::components::{Position, Name};
+use std::iter::{Copied, Zip};
+use std::slice::{Iter, IterMut};
+
+pub trait Query {
+    type Item<'a>;
+
+    fn fetch(archetype: &Archetype, index: usize) -> Self::Item<'_>;
+}
+
+impl Query for &Position {
+    type Item<'a> = &'a Position;
+
+    fn fetch(archetype: &Archetype, index: usize) -> Self::Item<'_> {
+        &archetype.positions[index]
+    }
+}
+
+impl Query for &Name {
+    type Item<'a> = &'a Name;
+
+    fn fetch(archetype: &Archetype, index: usize) -> Self::Item<'_> {
+        &archetype.names[index]
+    }
+}
+
+macro_rules! impl_query_for_tuple {
+    ($($component:ident),+) => {
+        impl<$($component: Query),+> Query for ($($component,)+) {
+            type Item<'a> = ($($component::Item<'a>,)+);
+
+            fn fetch(archetype: &Archetype, index: usize) -> Self::Item<'_> {
+                ($($component::fetch(archetype, index),)+)
+            }
+        }
+    };
+}
+
+impl_query_for_tuple!(A);
+impl_query_for_tuple!(A, B);
+impl_query_for_tuple!(A, B, C);
+impl_query_for_tuple!(A, B, C, D);
+impl_query_for_tuple!(A, B, C, D, E);
+impl_query_for_tuple!(A, B, C, D, E, F);
+
+// Mutable queries borrow disjoint columns, so they can't be built from
+// the single-column impls like the read-only tuples. The macro below
+// writes each one out instead: every column alone, and every pair of
+// columns in either order, each column read (`ref`) or written (`mut`).
+pub trait QueryMut {
+    type Item<'a>;
+    type Iter<'a>: Iterator<Item = (u32, Self::Item<'a>)>;
+
+    fn fetch_mut(archetype: &mut Archetype) -> Self::Iter<'_>;
+}
+
+macro_rules! column_item {
+    ($lt:lifetime, ref $component:ident) => { &$lt $component };
+    ($lt:lifetime, mut $component:ident) => { &$lt mut $component };
+}
+
+macro_rules! column_iter_type {
+    ($lt:lifetime, ref $component:ident) => { Iter<$lt, $component> };
+    ($lt:lifetime, mut $component:ident) => { IterMut<$lt, $component> };
+}
+
+macro_rules! column_iter {
+    ($archetype:ident.$column:ident, ref) => { $archetype.$column.iter() };
+    ($archetype:ident.$column:ident, mut) => { $archetype.$column.iter_mut() };
+}
+
+macro_rules! impl_query_mut {
+    ($access:ident $component:ident in $column:ident) => {
+        impl QueryMut for column_item!('_, $access $component) {
+            type Item<'a> = column_item!('a, $access $component);
+            type Iter<'a> = Zip<Copied<Iter<'a, u32>>, column_iter_type!('a, $access $component)>;
+
+            fn fetch_mut(archetype: &mut Archetype) -> Self::Iter<'_> {
+                archetype
+                    .entity_ids
+                    .iter()
+                    .copied()
+                    .zip(column_iter!(archetype.$column, $access))
+            }
+        }
+    };
+    ($access_a:ident $a:ident in $column_a:ident, $access_b:ident $b:ident in $column_b:ident) => {
+        impl QueryMut for (column_item!('_, $access_a $a), column_item!('_, $access_b $b)) {
+            type Item<'a> = (column_item!('a, $access_a $a), column_item!('a, $access_b $b));
+            type Iter<'a> = Zip<
+                Copied<Iter<'a, u32>>,
+                Zip<column_iter_type!('a, $access_a $a), column_iter_type!('a, $access_b $b)>,
+            >;
+
+            fn fetch_mut(archetype: &mut Archetype) -> Self::Iter<'_> {
+                archetype.entity_ids.iter().copied().zip(
+                    column_iter!(archetype.$column_a, $access_a)
+                        .zip(column_iter!(archetype.$column_b, $access_b)),
+                )
+            }
+        }
+    };
+}
+
+impl_query_mut!(ref Position in positions);
+impl_query_mut!(mut Position in positions);
+impl_query_mut!(ref Name in names);
+impl_query_mut!(mut Name in names);
+
+impl_query_mut!(ref Position in positions, ref Name in names);
+impl_query_mut!(mut Position in positions, ref Name in names);
+impl_query_mut!(ref Position in positions, mut Name in names);
+impl_query_mut!(mut Position in positions, mut Name in names);
+impl_query_mut!(ref Name in names, ref Position in positions);
+impl_query_mut!(mut Name in names, ref Position in positions);
+impl_query_mut!(ref Name in names, mut Position in positions);
+impl_query_mut!(mut Name in names, mut Position in positions);
@@ -15,7 +15,7 @@ impl TagManager {
     pub fn add_tag(&mut self, entity: u32, tag: &str) {
         self.tag_to_entities
             .entry(tag.to_string())
-            .or_insert_with(HashSet::new)
+            .or_default()
             .insert(entity);
     }
 
@@ -28,8 +28,20 @@ impl TagManager {
         }
     }
 
+    pub fn remove_entity(&mut self, entity: u32) {
+        self.tag_to_entities.retain(|_, entities| {
+            entities.remove(&entity);
+            !entities.is_empty()
+        });
+    }
+
     pub fn get_entities_with_tag(&self, tag: &str) -> Option<&HashSet<u32>> {
         self.tag_to_entities.get(tag)
     }
 }
 
+impl Default for TagManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,3 +1,4 @@
 pub mod ecs;
 pub mod archetypes;
 pub mod components;
+pub mod systems;
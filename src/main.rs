@@ -1,17 +1,14 @@
 mod modules;
-mod archetypes;
-mod components;
-mod systems;
-mod ecs;
 
-use components::{Position, Name};
-use systems::MovementSystem;
-use ecs::ECS;
+use rust_game::components::{Position, Name};
+use rust_game::systems::SystemSchedule;
+use rust_game::ecs::ECS;
 
 fn main() {
     env_logger::init();
     modules::core::initialize();
     let mut ecs = ECS::new();
+    let mut schedule = SystemSchedule::with_default_systems();
     ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Nathan".to_string()));
     ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Ronald".to_string()));
     ecs.tag_manager.add_tag(0, "player");
@@ -19,9 +16,7 @@ fn main() {
     ecs.tag_manager.add_tag(1, "player");
 
     // Update systems
-    for archetype in &mut ecs.archetypes {
-        MovementSystem::update(archetype);
-    }
+    schedule.run(&mut ecs, 1.0 / 60.0);
     if let Some(entities) =  ecs.tag_manager.get_entities_with_tag("player") {
         println!("Entities with player tag: {:?}", entities);
     }
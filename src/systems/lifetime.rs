@@ -0,0 +1,35 @@
+use crate::components::LifetimeComponent;
+use crate::ecs::{ECS, EcsCommands, EntityTable};
+
+// Archetypes have no column for lifetimes, so they live in an EntityTable
+// that drops them once their entity is gone, even if its id is reused.
+#[derive(Debug, Default)]
+pub struct LifetimeSystem {
+    pub lifetimes: EntityTable<LifetimeComponent>,
+}
+
+impl LifetimeSystem {
+    pub fn new() -> Self {
+        Self {
+            lifetimes: EntityTable::new(),
+        }
+    }
+
+    // Returns false if the entity does not exist.
+    pub fn set_lifetime(&mut self, ecs: &ECS, entity: u32, seconds: f32) -> bool {
+        self.lifetimes.insert(ecs, entity, LifetimeComponent { remaining: seconds })
+    }
+
+    pub fn update(&mut self, ecs: &ECS, dt: f32, commands: &mut EcsCommands) {
+        self.lifetimes.prune(ecs);
+        self.lifetimes.retain(|entity, lifetime| {
+            lifetime.remaining -= dt;
+            if lifetime.remaining <= 0.0 {
+                commands.despawn(entity);
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
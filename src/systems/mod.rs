@@ -1,3 +1,13 @@
+pub mod lifetime;
 pub mod movement;
+pub mod patrol;
+pub mod rng;
+pub mod schedule;
+pub mod timers;
 
+pub use lifetime::LifetimeSystem;
 pub use movement::MovementSystem;
+pub use patrol::PatrolSystem;
+pub use rng::RngService;
+pub use schedule::{Stage, SystemSchedule};
+pub use timers::Timers;
@@ -1,13 +1,13 @@
-use crate::archetypes::Archetype;
+use crate::components::Position;
+use crate::ecs::ECS;
 
 pub struct MovementSystem;
 
 impl MovementSystem {
-    pub fn update(archetype: &mut Archetype) {
-        for pos in archetype.positions.iter_mut() {
+    pub fn update(ecs: &mut ECS) {
+        for (_, pos) in ecs.query_mut::<&mut Position>() {
             pos.x += 0.1;
             pos.y += 0.1;
         }
     }
 }
-
@@ -0,0 +1,60 @@
+use crate::components::{PatrolComponent, Position};
+use crate::ecs::{ECS, EntityTable};
+
+// Kept in an EntityTable like the LifetimeSystem's lifetimes.
+#[derive(Debug, Default)]
+pub struct PatrolSystem {
+    pub patrols: EntityTable<PatrolComponent>,
+}
+
+impl PatrolSystem {
+    pub fn new() -> Self {
+        Self {
+            patrols: EntityTable::new(),
+        }
+    }
+
+    // Returns false if the entity does not exist.
+    pub fn set_patrol(&mut self, ecs: &ECS, entity: u32, patrol: PatrolComponent) -> bool {
+        self.patrols.insert(ecs, entity, patrol)
+    }
+
+    pub fn update(&mut self, ecs: &mut ECS, dt: f32) {
+        self.patrols.prune(ecs);
+        for (entity, patrol) in self.patrols.iter_mut() {
+            let Some(&(archetype_index, index_within_archetype)) = ecs.entity_to_location.get(&entity) else {
+                continue;
+            };
+            let position = &mut ecs.archetypes[archetype_index].positions[index_within_archetype];
+            Self::advance(patrol, position, patrol.speed * dt);
+        }
+    }
+
+    // Moves at most one lap of waypoints per update; any step left over
+    // after that is dropped. A negative or NaN step does not move.
+    fn advance(patrol: &mut PatrolComponent, position: &mut Position, step: f32) {
+        let mut step = step.max(0.0);
+        // Bounded so a loop of coincident waypoints can't spin forever
+        for _ in 0..patrol.waypoints.len() {
+            if patrol.is_finished() {
+                return;
+            }
+            let target = &patrol.waypoints[patrol.next_waypoint];
+            let (dx, dy) = (target.x - position.x, target.y - position.y);
+            let distance = (dx * dx + dy * dy).sqrt();
+            if distance > step {
+                position.x += dx / distance * step;
+                position.y += dy / distance * step;
+                return;
+            }
+
+            // Reached the waypoint; spend the rest of the step on the next one
+            *position = target.clone();
+            step -= distance;
+            patrol.next_waypoint += 1;
+            if patrol.is_finished() && patrol.looping {
+                patrol.next_waypoint = 0;
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// SplitMix64: small, fast and fully determined by the seed, so a whole
+// session can be replayed from the seed it started with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RngService {
+    seed: u64,
+    state: u64,
+}
+
+impl RngService {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    // Streams come from the master seed and the key only, so drawing from
+    // one stream (or from the master) never shifts another.
+    pub fn derive_stream(&self, key: &str) -> RngService {
+        // FNV-1a, which unlike the std hashers is stable across releases
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in key.bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        let mut seeder = RngService::new(self.seed ^ hash);
+        RngService::new(seeder.next_u64())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
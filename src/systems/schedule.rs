@@ -0,0 +1,64 @@
+use crate::ecs::{ECS, EcsCommands};
+use crate::systems::MovementSystem;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    PreUpdate,
+    Update,
+    Physics,
+    PostUpdate,
+    Render,
+}
+
+pub type System = Box<dyn FnMut(&mut ECS, &mut EcsCommands, f32)>;
+
+// Stages run in declaration order; systems within a stage run in the
+// order they were added. Commands queued during a stage are applied when
+// it ends, so later stages see the spawned and despawned entities.
+pub struct SystemSchedule {
+    stages: BTreeMap<Stage, Vec<System>>,
+    commands: EcsCommands,
+}
+
+impl SystemSchedule {
+    pub fn new() -> Self {
+        Self {
+            stages: BTreeMap::new(),
+            commands: EcsCommands::new(),
+        }
+    }
+
+    pub fn with_default_systems() -> Self {
+        let mut schedule = Self::new();
+        schedule.add_system(Stage::Update, |ecs, _, _| MovementSystem::update(ecs));
+        schedule
+    }
+
+    pub fn add_system<F>(&mut self, stage: Stage, system: F)
+    where
+        F: FnMut(&mut ECS, &mut EcsCommands, f32) + 'static,
+    {
+        self.stages.entry(stage).or_default().push(Box::new(system));
+    }
+
+    pub fn system_count(&self, stage: Stage) -> usize {
+        self.stages.get(&stage).map_or(0, Vec::len)
+    }
+
+    pub fn run(&mut self, ecs: &mut ECS, dt: f32) {
+        ecs.events.update();
+        for systems in self.stages.values_mut() {
+            for system in systems.iter_mut() {
+                system(ecs, &mut self.commands, dt);
+            }
+            ecs.apply_commands(&mut self.commands);
+        }
+    }
+}
+
+impl Default for SystemSchedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
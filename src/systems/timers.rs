@@ -0,0 +1,83 @@
+// Shortest repeat interval; a zero interval would fire without end.
+pub const MIN_INTERVAL: f32 = 0.001;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Timer {
+    key: String,
+    remaining: f32,
+    interval: Option<f32>,
+}
+
+// Timers are kept in insertion order so the fired list is deterministic
+// for a given sequence of updates.
+#[derive(Debug, Default)]
+pub struct Timers {
+    timers: Vec<Timer>,
+}
+
+impl Timers {
+    pub fn new() -> Self {
+        Self { timers: Vec::new() }
+    }
+
+    pub fn after(&mut self, seconds: f32, key: &str) {
+        // NaN would never count down, so treat it as due immediately
+        self.schedule(key, seconds.max(0.0), None);
+    }
+
+    pub fn every(&mut self, seconds: f32, key: &str) {
+        let interval = seconds.max(MIN_INTERVAL);
+        self.schedule(key, interval, Some(interval));
+    }
+
+    pub fn cancel(&mut self, key: &str) {
+        self.timers.retain(|timer| timer.key != key);
+    }
+
+    pub fn is_scheduled(&self, key: &str) -> bool {
+        self.timers.iter().any(|timer| timer.key == key)
+    }
+
+    // Returns each timer that fired with how many times it fired this step.
+    // A non-finite or negative dt is ignored.
+    pub fn update(&mut self, dt: f32) -> Vec<(String, u32)> {
+        let mut fired = Vec::new();
+        if !dt.is_finite() || dt < 0.0 {
+            return fired;
+        }
+        self.timers.retain_mut(|timer| {
+            timer.remaining -= dt;
+            if timer.remaining > 0.0 {
+                return true;
+            }
+            match timer.interval {
+                Some(interval) => {
+                    // Every multiple of the interval passed this step counts once
+                    let overshoot = -timer.remaining;
+                    let count = (overshoot / interval).floor() + 1.0;
+                    timer.remaining = interval - overshoot % interval;
+                    fired.push((timer.key.clone(), count as u32));
+                    true
+                }
+                None => {
+                    fired.push((timer.key.clone(), 1));
+                    false
+                }
+            }
+        });
+        fired
+    }
+
+    fn schedule(&mut self, key: &str, seconds: f32, interval: Option<f32>) {
+        let timer = Timer {
+            key: key.to_string(),
+            remaining: seconds,
+            interval,
+        };
+        if let Some(existing) = self.timers.iter_mut().find(|timer| timer.key == key) {
+            *existing = timer;
+        } else {
+            self.timers.push(timer);
+        }
+    }
+}
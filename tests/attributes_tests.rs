@@ -0,0 +1,55 @@
+use rust_game::components::{AttributesComponent, Bound};
+
+fn health_attributes(health: f32, max_health: f32) -> AttributesComponent {
+    let mut attributes = AttributesComponent::new();
+    attributes.set("max_health", max_health);
+    attributes.set("health", health);
+    attributes.register_clamp(
+        "health",
+        Bound::Value(0.0),
+        Bound::Attribute("max_health".to_string()),
+    );
+    attributes
+}
+
+#[test]
+fn test_modify_health_floors_at_zero() {
+    let mut attributes = health_attributes(5.0, 100.0);
+
+    assert_eq!(attributes.modify("health", -10.0), 0.0);
+    assert_eq!(attributes.get("health"), Some(0.0));
+}
+
+#[test]
+fn test_registered_max_prevents_overheal() {
+    let mut attributes = health_attributes(90.0, 100.0);
+
+    assert_eq!(attributes.modify("health", 25.0), 100.0);
+
+    // The cap follows max_health as it changes
+    attributes.set("max_health", 150.0);
+    assert_eq!(attributes.modify("health", 25.0), 125.0);
+}
+
+#[test]
+fn test_register_clamp_applies_to_stored_value() {
+    let mut attributes = AttributesComponent::new();
+    attributes.set("speed", 12.0);
+    attributes.register_clamp("speed", Bound::Value(0.0), Bound::Value(10.0));
+
+    assert_eq!(attributes.get("speed"), Some(10.0));
+}
+
+#[test]
+fn test_unclamped_modify_and_get_clamped() {
+    let mut attributes = AttributesComponent::new();
+
+    // Unset keys start at 0
+    assert_eq!(attributes.modify("ammo", -3.0), -3.0);
+    assert_eq!(attributes.get_clamped("ammo", 0.0, 10.0), Some(0.0));
+    assert_eq!(attributes.get_clamped("missing", 0.0, 10.0), None);
+
+    let mut keys: Vec<&str> = attributes.keys().collect();
+    keys.sort();
+    assert_eq!(keys, vec!["ammo"]);
+}
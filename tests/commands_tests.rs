@@ -0,0 +1,55 @@
+use rust_game::ecs::{ECS, EcsCommands};
+use rust_game::components::{Position, Name};
+
+#[test]
+fn test_commands_are_deferred_until_applied() {
+    let mut ecs = ECS::new();
+    let mut commands = EcsCommands::new();
+
+    commands.spawn(Position { x: 1.0, y: 1.0 }, Name("Queued".to_string()));
+    assert_eq!(commands.len(), 1);
+    assert!(ecs.entity_to_location.is_empty());
+
+    ecs.apply_commands(&mut commands);
+
+    assert!(commands.is_empty());
+    assert_eq!(ecs.entity_to_location.len(), 1);
+    let (position, name) = ecs.find_entity_components(0).unwrap();
+    assert_eq!(position, &Position { x: 1.0, y: 1.0 });
+    assert_eq!(name, &Name("Queued".to_string()));
+}
+
+#[test]
+fn test_despawn_queued_during_iteration() {
+    let mut ecs = ECS::new();
+    for i in 0..4 {
+        ecs.add_entity(Position { x: i as f32, y: 0.0 }, Name(format!("Entity{}", i)));
+    }
+
+    // Queue despawns for every entity past x = 1.5 while borrowing the archetypes
+    let mut commands = EcsCommands::new();
+    for archetype in &ecs.archetypes {
+        for (id, position) in archetype.entity_ids.iter().zip(archetype.positions.iter()) {
+            if position.x > 1.5 {
+                commands.despawn(*id);
+            }
+        }
+    }
+    commands.spawn(Position { x: 9.0, y: 9.0 }, Name("Replacement".to_string()));
+    ecs.apply_commands(&mut commands);
+
+    assert_eq!(ecs.entity_to_location.len(), 3);
+    assert_eq!(ecs.find_entity_components(0).unwrap().1, &Name("Entity0".to_string()));
+    assert_eq!(ecs.find_entity_components(1).unwrap().1, &Name("Entity1".to_string()));
+
+    // The spawn ran after the despawns, so it reuses one of the freed ids
+    let (position, name) = ecs
+        .archetypes
+        .iter()
+        .flat_map(|archetype| archetype.positions.iter().zip(archetype.names.iter()))
+        .find(|(_, name)| name.0 == "Replacement")
+        .unwrap();
+    assert_eq!(position, &Position { x: 9.0, y: 9.0 });
+    assert_eq!(name, &Name("Replacement".to_string()));
+    assert_eq!(ecs.entity_manager.next_entity_id, 4);
+}
@@ -0,0 +1,13 @@
+use rust_game::archetypes::Archetype;
+use rust_game::components::{Position, Name};
+use rust_game::ecs::ECS;
+
+// Places a new entity in its own archetype so queries have to walk more than one
+pub fn add_entity_in_new_archetype(ecs: &mut ECS, position: Position, name: Name) -> u32 {
+    let id = ecs.entity_manager.create_entity();
+    let mut archetype = Archetype::new();
+    archetype.add_entity(id, position, name);
+    ecs.archetypes.push(archetype);
+    ecs.entity_to_location.insert(id, (ecs.archetypes.len() - 1, 0));
+    id
+}
@@ -1,6 +1,9 @@
-use rust_game::ecs::ECS;
+use rust_game::ecs::{ECS, EntityEvent, EventReader};
 use rust_game::components::{Position, Name};
 
+mod common;
+use common::add_entity_in_new_archetype;
+
 #[test]
 fn test_add_entity() {
     let mut ecs = ECS::new();
@@ -8,11 +11,10 @@ fn test_add_entity() {
     // Add an entity
     let position = Position { x: 1.0, y: 2.0 };
     let name = Name("Test Entity".to_string());
-    ecs.add_entity(position.clone(), name.clone());
+    let id = ecs.add_entity(position.clone(), name.clone());
 
     // Verify that the entity was added
     assert_eq!(ecs.entity_to_location.len(), 1);
-    let id = ecs.entity_manager.next_entity_id - 1;
     assert!(ecs.entity_to_location.contains_key(&id));
 
     // Check the entity's components
@@ -38,10 +40,9 @@ fn test_find_entity() {
     // Add an entity
     let position = Position { x: 5.0, y: 10.0 };
     let name = Name("Finder".to_string());
-    ecs.add_entity(position.clone(), name.clone());
+    let id = ecs.add_entity(position.clone(), name.clone());
 
     // Find the entity
-    let id = ecs.entity_manager.next_entity_id - 1;
     let archetype = ecs.find_entity(id);
 
     assert!(archetype.is_some());
@@ -54,10 +55,9 @@ fn test_find_entity_components() {
     // Add an entity
     let position = Position { x: 3.0, y: 4.0 };
     let name = Name("Component Checker".to_string());
-    ecs.add_entity(position.clone(), name.clone());
+    let id = ecs.add_entity(position.clone(), name.clone());
 
     // Find the components
-    let id = ecs.entity_manager.next_entity_id - 1;
     let components = ecs.find_entity_components(id);
 
     assert!(components.is_some());
@@ -73,10 +73,9 @@ fn test_remove_entity() {
     // Add an entity
     let position = Position { x: 7.0, y: 8.0 };
     let name = Name("Removable".to_string());
-    ecs.add_entity(position.clone(), name.clone());
+    let id = ecs.add_entity(position.clone(), name.clone());
 
     // Verify the entity exists in the archetype before removal
-    let id = ecs.entity_manager.next_entity_id - 1;
     let location = ecs.entity_to_location.get(&id).unwrap();
     let (archetype_index, _) = *location;
     let archetype = &ecs.archetypes[archetype_index];
@@ -105,17 +104,189 @@ fn test_reuse_entity_id() {
     // Add and remove an entity
     let position = Position { x: 2.0, y: 2.0 };
     let name = Name("Reusable".to_string());
-    ecs.add_entity(position.clone(), name.clone());
-    let id = ecs.entity_manager.next_entity_id - 1;
+    let id = ecs.add_entity(position.clone(), name.clone());
     ecs.remove_entity(id);
 
     // Add a new entity and check ID reuse
     let new_position = Position { x: 9.0, y: 9.0 };
     let new_name = Name("Reused".to_string());
-    ecs.add_entity(new_position.clone(), new_name.clone());
-    let new_id = ecs.entity_manager.next_entity_id - 1;
+    let new_id = ecs.add_entity(new_position.clone(), new_name.clone());
 
     assert_eq!(id, new_id);
     assert!(ecs.find_entity_components(new_id).is_some());
 }
 
+
+#[test]
+fn test_tagged_with_positions_across_archetypes() {
+    let mut ecs = ECS::new();
+    ecs.add_entity(Position { x: 1.0, y: 1.0 }, Name("Enemy A".to_string()));
+    ecs.add_entity(Position { x: 2.0, y: 2.0 }, Name("Ally".to_string()));
+    let enemy_a = 0;
+    let ally = 1;
+    let enemy_b = add_entity_in_new_archetype(&mut ecs, Position { x: 3.0, y: 3.0 }, Name("Enemy B".to_string()));
+    assert_ne!(ecs.entity_to_location[&enemy_a].0, ecs.entity_to_location[&enemy_b].0);
+
+    ecs.tag_manager.add_tag(enemy_a, "enemy");
+    ecs.tag_manager.add_tag(enemy_b, "enemy");
+    ecs.tag_manager.add_tag(ally, "ally");
+
+    let mut enemies: Vec<(u32, Position)> = ecs
+        .tagged_with_positions("enemy")
+        .map(|(id, position)| (id, position.clone()))
+        .collect();
+    enemies.sort_by_key(|(id, _)| *id);
+
+    assert_eq!(
+        enemies,
+        vec![
+            (enemy_a, Position { x: 1.0, y: 1.0 }),
+            (enemy_b, Position { x: 3.0, y: 3.0 }),
+        ]
+    );
+    assert_eq!(ecs.tagged_with_positions("missing").count(), 0);
+}
+
+#[test]
+fn test_tagged_with_positions_mut() {
+    let mut ecs = ECS::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Enemy A".to_string()));
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Ally".to_string()));
+    let enemy_b = add_entity_in_new_archetype(&mut ecs, Position { x: 5.0, y: 5.0 }, Name("Enemy B".to_string()));
+    ecs.tag_manager.add_tag(0, "enemy");
+    ecs.tag_manager.add_tag(enemy_b, "enemy");
+
+    for (_, position) in ecs.tagged_with_positions_mut("enemy") {
+        position.x += 1.0;
+    }
+
+    assert_eq!(ecs.find_entity_components(0).unwrap().0, &Position { x: 1.0, y: 0.0 });
+    assert_eq!(ecs.find_entity_components(1).unwrap().0, &Position { x: 0.0, y: 0.0 });
+    assert_eq!(ecs.find_entity_components(enemy_b).unwrap().0, &Position { x: 6.0, y: 5.0 });
+}
+
+#[test]
+fn test_spawn_and_despawn_events() {
+    let mut ecs = ECS::new();
+    let mut reader = EventReader::new();
+    let id = ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Short Lived".to_string()));
+    ecs.remove_entity(id);
+
+    let events: Vec<EntityEvent> = reader.read(&ecs.events).copied().collect();
+    assert_eq!(events, vec![EntityEvent::Spawned { id }, EntityEvent::Despawned { id }]);
+
+    // A reader only sees each event once, and removing an unknown entity emits nothing
+    ecs.remove_entity(id);
+    assert_eq!(reader.read(&ecs.events).count(), 0);
+}
+
+#[test]
+fn test_every_reader_sees_every_event() {
+    let mut ecs = ECS::new();
+    let mut ui_reader = EventReader::new();
+    let mut audio_reader = EventReader::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Enemy".to_string()));
+
+    assert_eq!(ui_reader.read(&ecs.events).count(), 1);
+    assert_eq!(audio_reader.read(&ecs.events).count(), 1);
+}
+
+#[test]
+fn test_events_are_dropped_after_two_updates() {
+    let mut ecs = ECS::new();
+    let mut reader = EventReader::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Old".to_string()));
+
+    // Still readable one frame later
+    ecs.events.update();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("New".to_string()));
+    assert_eq!(ecs.events.len(), 2);
+
+    // Unread events do not pile up
+    ecs.events.update();
+    ecs.events.update();
+    assert!(ecs.events.is_empty());
+    assert_eq!(reader.read(&ecs.events).count(), 0);
+
+    // A lagging reader picks up from whatever is still buffered
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Latest".to_string()));
+    let events: Vec<EntityEvent> = reader.read(&ecs.events).copied().collect();
+    assert_eq!(events, vec![EntityEvent::Spawned { id: 2 }]);
+}
+
+#[test]
+fn test_silent_spawn_emits_no_spawn_event() {
+    let mut ecs = ECS::new();
+    let mut reader = EventReader::new();
+    ecs.add_entity_silent(Position { x: 0.0, y: 0.0 }, Name("Spark".to_string()));
+    assert!(ecs.find_entity_components(0).is_some());
+    assert_eq!(reader.read(&ecs.events).count(), 0);
+
+    // The despawn is still reported
+    ecs.remove_entity(0);
+    let events: Vec<EntityEvent> = reader.read(&ecs.events).copied().collect();
+    assert_eq!(events, vec![EntityEvent::Despawned { id: 0 }]);
+}
+
+#[test]
+fn test_remove_entity_keeps_other_locations_valid() {
+    let mut ecs = ECS::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("First".to_string()));
+    ecs.add_entity(Position { x: 1.0, y: 1.0 }, Name("Second".to_string()));
+    ecs.add_entity(Position { x: 2.0, y: 2.0 }, Name("Third".to_string()));
+
+    // Removing the first entity swaps the last one into its slot
+    ecs.remove_entity(0);
+
+    let (position, name) = ecs.find_entity_components(2).unwrap();
+    assert_eq!(position, &Position { x: 2.0, y: 2.0 });
+    assert_eq!(name, &Name("Third".to_string()));
+    let (position, name) = ecs.find_entity_components(1).unwrap();
+    assert_eq!(position, &Position { x: 1.0, y: 1.0 });
+    assert_eq!(name, &Name("Second".to_string()));
+}
+
+#[test]
+fn test_tagged_with_positions_ignores_recycled_ids() {
+    let mut ecs = ECS::new();
+    ecs.add_entity(Position { x: 1.0, y: 1.0 }, Name("Fallen".to_string()));
+    ecs.add_entity(Position { x: 2.0, y: 2.0 }, Name("Standing".to_string()));
+    ecs.tag_manager.add_tag(0, "enemy");
+    ecs.tag_manager.add_tag(1, "enemy");
+
+    // The removed entity's id is reused, but its tags went with it
+    ecs.remove_entity(0);
+    ecs.add_entity(Position { x: 3.0, y: 3.0 }, Name("Player".to_string()));
+    assert!(ecs.find_entity_components(0).is_some());
+
+    let enemies: Vec<(u32, &Position)> = ecs.tagged_with_positions("enemy").collect();
+    assert_eq!(enemies, vec![(1, &Position { x: 2.0, y: 2.0 })]);
+}
+
+#[test]
+fn test_tagged_with_positions_mut_picks_scattered_rows() {
+    let mut ecs = ECS::new();
+    for i in 0..6 {
+        ecs.add_entity(Position { x: i as f32, y: 0.0 }, Name(format!("Entity{}", i)));
+    }
+    let far = add_entity_in_new_archetype(&mut ecs, Position { x: 10.0, y: 0.0 }, Name("Far".to_string()));
+    for id in [4, 1, far, 2] {
+        ecs.tag_manager.add_tag(id, "enemy");
+    }
+
+    let mut touched: Vec<u32> = ecs
+        .tagged_with_positions_mut("enemy")
+        .map(|(id, position)| {
+            position.y = 1.0;
+            id
+        })
+        .collect();
+    touched.sort();
+    assert_eq!(touched, vec![1, 2, 4, far]);
+
+    for (id, (position, _)) in ecs.query::<(&Position, &Name)>() {
+        let expected = if [1, 2, 4, far].contains(&id) { 1.0 } else { 0.0 };
+        assert_eq!(position.y, expected, "entity {}", id);
+    }
+    assert_eq!(ecs.tagged_with_positions_mut("missing").count(), 0);
+}
@@ -0,0 +1,110 @@
+use rust_game::ecs::{ECS, EcsCommands};
+use rust_game::components::{Position, Name};
+use rust_game::systems::LifetimeSystem;
+
+const DT: f32 = 1.0 / 60.0;
+
+#[test]
+fn test_entity_expires_after_lifetime() {
+    let mut ecs = ECS::new();
+    let mut commands = EcsCommands::new();
+    let mut lifetimes = LifetimeSystem::new();
+
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Spark".to_string()));
+    ecs.add_entity(Position { x: 1.0, y: 1.0 }, Name("Rock".to_string()));
+    lifetimes.set_lifetime(&ecs, 0, 0.1);
+
+    // 5 ticks is short of 0.1s
+    for _ in 0..5 {
+        lifetimes.update(&ecs, DT, &mut commands);
+        ecs.apply_commands(&mut commands);
+    }
+    assert!(ecs.find_entity_components(0).is_some());
+
+    for _ in 0..2 {
+        lifetimes.update(&ecs, DT, &mut commands);
+        ecs.apply_commands(&mut commands);
+    }
+    assert!(ecs.find_entity_components(0).is_none());
+    assert!(lifetimes.lifetimes.is_empty());
+
+    // Entities without a lifetime are untouched
+    assert!(ecs.find_entity_components(1).is_some());
+}
+
+#[test]
+fn test_recycled_id_survives_stale_lifetime() {
+    let mut ecs = ECS::new();
+    let mut commands = EcsCommands::new();
+    let mut lifetimes = LifetimeSystem::new();
+
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Pickup".to_string()));
+    lifetimes.set_lifetime(&ecs, 0, 0.1);
+
+    // Removed some other way before it expired, and its id is reused
+    ecs.remove_entity(0);
+    ecs.add_entity(Position { x: 1.0, y: 1.0 }, Name("Player".to_string()));
+    assert!(ecs.find_entity_components(0).is_some());
+
+    for _ in 0..10 {
+        lifetimes.update(&ecs, DT, &mut commands);
+        ecs.apply_commands(&mut commands);
+    }
+
+    assert_eq!(ecs.find_entity_components(0).unwrap().1, &Name("Player".to_string()));
+    assert!(lifetimes.lifetimes.is_empty());
+}
+
+#[test]
+fn test_recycled_id_keeps_its_own_lifetime() {
+    let mut ecs = ECS::new();
+    let mut commands = EcsCommands::new();
+    let mut lifetimes = LifetimeSystem::new();
+
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Spark".to_string()));
+    lifetimes.set_lifetime(&ecs, 0, 1.0);
+    ecs.remove_entity(0);
+
+    // The new entity's lifetime is set before the old despawn is processed
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Ember".to_string()));
+    lifetimes.set_lifetime(&ecs, 0, 0.05);
+
+    for _ in 0..4 {
+        lifetimes.update(&ecs, DT, &mut commands);
+        ecs.apply_commands(&mut commands);
+    }
+    assert!(ecs.find_entity_components(0).is_none());
+}
+
+#[test]
+fn test_recycled_id_survives_after_despawn_event_expires() {
+    let mut ecs = ECS::new();
+    let mut commands = EcsCommands::new();
+    let mut lifetimes = LifetimeSystem::new();
+
+    let id = ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Pickup".to_string()));
+    lifetimes.set_lifetime(&ecs, id, 0.05);
+    ecs.remove_entity(id);
+
+    // The Despawned event is gone before the system next runs
+    ecs.events.update();
+    ecs.events.update();
+    let new_id = ecs.add_entity(Position { x: 1.0, y: 1.0 }, Name("Player".to_string()));
+    assert_eq!(new_id, id);
+
+    for _ in 0..10 {
+        lifetimes.update(&ecs, DT, &mut commands);
+        ecs.apply_commands(&mut commands);
+    }
+    assert_eq!(ecs.find_entity_components(new_id).unwrap().1, &Name("Player".to_string()));
+    assert!(lifetimes.lifetimes.is_empty());
+}
+
+#[test]
+fn test_set_lifetime_rejects_missing_entity() {
+    let ecs = ECS::new();
+    let mut lifetimes = LifetimeSystem::new();
+
+    assert!(!lifetimes.set_lifetime(&ecs, 0, 1.0));
+    assert!(lifetimes.lifetimes.is_empty());
+}
@@ -0,0 +1,116 @@
+use rust_game::ecs::ECS;
+use rust_game::components::{Position, Name, PatrolComponent};
+use rust_game::systems::PatrolSystem;
+
+const DT: f32 = 0.1;
+
+fn guard_route(looping: bool) -> PatrolComponent {
+    PatrolComponent::new(
+        vec![Position { x: 1.0, y: 0.0 }, Position { x: 1.0, y: 1.0 }],
+        1.0,
+        looping,
+    )
+}
+
+fn position_of(ecs: &ECS, id: u32) -> Position {
+    ecs.find_entity_components(id).unwrap().0.clone()
+}
+
+fn assert_near(actual: Position, expected: Position) {
+    assert!(
+        (actual.x - expected.x).abs() < 1e-4 && (actual.y - expected.y).abs() < 1e-4,
+        "{:?} is not near {:?}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn test_patroller_reaches_and_passes_first_waypoint() {
+    let mut ecs = ECS::new();
+    let mut patrols = PatrolSystem::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Guard".to_string()));
+    patrols.set_patrol(&ecs, 0, guard_route(false));
+
+    // Halfway to the first waypoint
+    for _ in 0..5 {
+        patrols.update(&mut ecs, DT);
+    }
+    assert_near(position_of(&ecs, 0), Position { x: 0.5, y: 0.0 });
+    assert_eq!(patrols.patrols.get(&ecs, 0).unwrap().next_waypoint, 0);
+
+    // Past the first waypoint and heading up towards the second
+    for _ in 0..8 {
+        patrols.update(&mut ecs, DT);
+    }
+    assert_near(position_of(&ecs, 0), Position { x: 1.0, y: 0.3 });
+    assert_eq!(patrols.patrols.get(&ecs, 0).unwrap().next_waypoint, 1);
+
+    // Stops on the last waypoint when not looping
+    for _ in 0..20 {
+        patrols.update(&mut ecs, DT);
+    }
+    assert_near(position_of(&ecs, 0), Position { x: 1.0, y: 1.0 });
+    assert!(patrols.patrols.get(&ecs, 0).unwrap().is_finished());
+}
+
+#[test]
+fn test_looping_patrol_returns_to_first_waypoint() {
+    let mut ecs = ECS::new();
+    let mut patrols = PatrolSystem::new();
+    ecs.add_entity(Position { x: 1.0, y: 0.0 }, Name("Guard".to_string()));
+    patrols.set_patrol(&ecs, 0, guard_route(true));
+
+    // Up to the second waypoint, then back down towards the first
+    for _ in 0..15 {
+        patrols.update(&mut ecs, DT);
+    }
+    assert_near(position_of(&ecs, 0), Position { x: 1.0, y: 0.5 });
+    assert_eq!(patrols.patrols.get(&ecs, 0).unwrap().next_waypoint, 0);
+    assert!(!patrols.patrols.get(&ecs, 0).unwrap().is_finished());
+}
+
+#[test]
+fn test_recycled_id_does_not_inherit_patrol() {
+    let mut ecs = ECS::new();
+    let mut patrols = PatrolSystem::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Guard".to_string()));
+    patrols.set_patrol(&ecs, 0, guard_route(false));
+
+    ecs.remove_entity(0);
+    ecs.add_entity(Position { x: 5.0, y: 5.0 }, Name("Crate".to_string()));
+    patrols.update(&mut ecs, DT);
+
+    assert!(patrols.patrols.is_empty());
+    assert_eq!(position_of(&ecs, 0), Position { x: 5.0, y: 5.0 });
+}
+
+#[test]
+fn test_negative_speed_and_nan_step_do_not_move() {
+    let mut ecs = ECS::new();
+    let mut patrols = PatrolSystem::new();
+    let id = ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Guard".to_string()));
+    patrols.set_patrol(&ecs, id, PatrolComponent::new(vec![Position { x: 1.0, y: 0.0 }], -1.0, false));
+
+    patrols.update(&mut ecs, DT);
+    patrols.update(&mut ecs, f32::NAN);
+    assert_eq!(position_of(&ecs, id), Position { x: 0.0, y: 0.0 });
+    assert_eq!(patrols.patrols.get(&ecs, id).unwrap().next_waypoint, 0);
+}
+
+#[test]
+fn test_recycled_id_after_despawn_event_expires() {
+    let mut ecs = ECS::new();
+    let mut patrols = PatrolSystem::new();
+    let id = ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Guard".to_string()));
+    patrols.set_patrol(&ecs, id, guard_route(false));
+
+    ecs.remove_entity(id);
+    ecs.events.update();
+    ecs.events.update();
+    let new_id = ecs.add_entity(Position { x: 5.0, y: 5.0 }, Name("Crate".to_string()));
+    patrols.update(&mut ecs, DT);
+
+    assert!(patrols.patrols.is_empty());
+    assert_eq!(position_of(&ecs, new_id), Position { x: 5.0, y: 5.0 });
+}
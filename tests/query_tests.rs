@@ -0,0 +1,129 @@
+use rust_game::ecs::ECS;
+use rust_game::components::{Position, Name};
+
+mod common;
+use common::add_entity_in_new_archetype;
+
+fn ecs_with_two_archetypes() -> ECS {
+    let mut ecs = ECS::new();
+    ecs.add_entity(Position { x: 1.0, y: 1.0 }, Name("First".to_string()));
+    ecs.add_entity(Position { x: 2.0, y: 2.0 }, Name("Second".to_string()));
+    add_entity_in_new_archetype(&mut ecs, Position { x: 3.0, y: 3.0 }, Name("Third".to_string()));
+    ecs
+}
+
+#[test]
+fn test_query_single_component() {
+    let ecs = ecs_with_two_archetypes();
+
+    let names: Vec<(u32, &Name)> = ecs.query::<&Name>().collect();
+
+    assert_eq!(
+        names,
+        vec![
+            (0, &Name("First".to_string())),
+            (1, &Name("Second".to_string())),
+            (2, &Name("Third".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn test_query_tuple_matches_find_entity_components() {
+    let ecs = ecs_with_two_archetypes();
+
+    let mut count = 0;
+    for (id, (position, name)) in ecs.query::<(&Position, &Name)>() {
+        assert_eq!(ecs.find_entity_components(id), Some((position, name)));
+        count += 1;
+    }
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn test_query_mut_updates_every_archetype() {
+    let mut ecs = ecs_with_two_archetypes();
+
+    for (_, position) in ecs.query_mut::<&mut Position>() {
+        position.y = 0.0;
+    }
+    for (id, name) in ecs.query_mut::<&mut Name>() {
+        name.0 = format!("Entity{}", id);
+    }
+
+    for (id, (position, name)) in ecs.query::<(&Position, &Name)>() {
+        assert_eq!(position.y, 0.0);
+        assert_eq!(name, &Name(format!("Entity{}", id)));
+    }
+}
+
+#[test]
+fn test_query_empty_ecs() {
+    let mut ecs = ECS::new();
+    assert_eq!(ecs.query::<(&Position, &Name)>().count(), 0);
+    assert_eq!(ecs.query_mut::<&mut Position>().count(), 0);
+}
+
+#[test]
+fn test_query_mut_mixed_tuples() {
+    let mut ecs = ecs_with_two_archetypes();
+
+    for (_, (position, name)) in ecs.query_mut::<(&mut Position, &Name)>() {
+        position.x = name.0.len() as f32;
+    }
+    for (_, (position, name)) in ecs.query_mut::<(&Position, &mut Name)>() {
+        name.0 = format!("{}@{}", name.0, position.x);
+    }
+    for (id, (position, name)) in ecs.query_mut::<(&mut Position, &mut Name)>() {
+        position.y = id as f32;
+        name.0.push('!');
+    }
+
+    let results: Vec<(u32, Position, String)> = ecs
+        .query::<(&Position, &Name)>()
+        .map(|(id, (position, name))| (id, position.clone(), name.0.clone()))
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            (0, Position { x: 5.0, y: 0.0 }, "First@5!".to_string()),
+            (1, Position { x: 6.0, y: 1.0 }, "Second@6!".to_string()),
+            (2, Position { x: 5.0, y: 2.0 }, "Third@5!".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_query_mut_any_column_order() {
+    let mut ecs = ecs_with_two_archetypes();
+
+    for (_, (name, position)) in ecs.query_mut::<(&Name, &mut Position)>() {
+        position.x = name.0.len() as f32;
+    }
+    for (_, (name, position)) in ecs.query_mut::<(&mut Name, &Position)>() {
+        name.0 = format!("{}@{}", name.0, position.x);
+    }
+    for (id, (name, position)) in ecs.query_mut::<(&mut Name, &mut Position)>() {
+        position.y = id as f32;
+        name.0.push('!');
+    }
+
+    // Read-only shapes work through query_mut too
+    let names: Vec<(u32, String)> = ecs
+        .query_mut::<&Name>()
+        .map(|(id, name)| (id, name.0.clone()))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            (0, "First@5!".to_string()),
+            (1, "Second@6!".to_string()),
+            (2, "Third@5!".to_string()),
+        ]
+    );
+    let positions: Vec<Position> = ecs
+        .query_mut::<(&Name, &Position)>()
+        .map(|(_, (_, position))| position.clone())
+        .collect();
+    assert_eq!(positions[1], Position { x: 6.0, y: 1.0 });
+}
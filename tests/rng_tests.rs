@@ -0,0 +1,52 @@
+use rust_game::systems::RngService;
+
+fn draw(rng: &mut RngService, count: usize) -> Vec<u64> {
+    (0..count).map(|_| rng.next_u64()).collect()
+}
+
+#[test]
+fn test_same_seed_same_sequence() {
+    let mut first = RngService::new(42);
+    let mut second = RngService::new(42);
+    assert_eq!(draw(&mut first, 100), draw(&mut second, 100));
+
+    let mut other = RngService::new(43);
+    assert_ne!(draw(&mut RngService::new(42), 100), draw(&mut other, 100));
+}
+
+#[test]
+fn test_derived_streams_are_reproducible_and_distinct() {
+    let master = RngService::new(7);
+    let mut particles = master.derive_stream("particles");
+    let mut weapons = master.derive_stream("weapons");
+
+    assert_eq!(draw(&mut particles, 50), draw(&mut master.derive_stream("particles"), 50));
+    assert_ne!(draw(&mut particles, 50), draw(&mut weapons, 50));
+    assert_ne!(
+        draw(&mut RngService::new(8).derive_stream("particles"), 50),
+        draw(&mut master.derive_stream("particles"), 50)
+    );
+}
+
+#[test]
+fn test_derived_streams_are_independent_of_consumption() {
+    let mut master = RngService::new(99);
+    let before = draw(&mut master.derive_stream("burst"), 20);
+
+    // Drawing from the master or a sibling stream doesn't shift the burst stream
+    draw(&mut master, 1000);
+    draw(&mut master.derive_stream("trees"), 1000);
+
+    assert_eq!(draw(&mut master.derive_stream("burst"), 20), before);
+}
+
+#[test]
+fn test_float_ranges() {
+    let mut rng = RngService::new(1);
+    for _ in 0..1000 {
+        let unit = rng.next_f32();
+        assert!((0.0..1.0).contains(&unit));
+        let spread = rng.range_f32(-2.0, 3.0);
+        assert!((-2.0..3.0).contains(&spread));
+    }
+}
@@ -0,0 +1,128 @@
+use rust_game::ecs::{ECS, EcsCommands};
+use rust_game::components::{Position, Name};
+use rust_game::systems::{LifetimeSystem, Stage, SystemSchedule};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DT: f32 = 1.0 / 60.0;
+
+#[test]
+fn test_default_schedule_runs_movement() {
+    let mut ecs = ECS::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Mover".to_string()));
+    let mut schedule = SystemSchedule::with_default_systems();
+    assert_eq!(schedule.system_count(Stage::Update), 1);
+
+    schedule.run(&mut ecs, DT);
+
+    let (position, _) = ecs.find_entity_components(0).unwrap();
+    assert_eq!(position, &Position { x: 0.1, y: 0.1 });
+}
+
+#[test]
+fn test_pre_update_runs_before_movement() {
+    let mut ecs = ECS::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Teleported".to_string()));
+    let mut schedule = SystemSchedule::with_default_systems();
+
+    // Registered after movement, but its stage runs first
+    schedule.add_system(Stage::PreUpdate, |ecs: &mut ECS, _: &mut EcsCommands, _: f32| {
+        for position in ecs.archetypes.iter_mut().flat_map(|archetype| archetype.positions.iter_mut()) {
+            *position = Position { x: 10.0, y: 10.0 };
+        }
+    });
+    schedule.run(&mut ecs, DT);
+
+    // Movement saw the teleported position and moved on from there
+    let (position, _) = ecs.find_entity_components(0).unwrap();
+    assert_eq!(position, &Position { x: 10.1, y: 10.1 });
+}
+
+#[test]
+fn test_stages_run_in_order() {
+    let mut ecs = ECS::new();
+    let mut schedule = SystemSchedule::new();
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    for (stage, label) in [
+        (Stage::Render, "render"),
+        (Stage::PostUpdate, "post_update"),
+        (Stage::Physics, "physics"),
+        (Stage::Update, "update"),
+        (Stage::PreUpdate, "pre_update"),
+        (Stage::Update, "update_second"),
+    ] {
+        let log = Rc::clone(&log);
+        schedule.add_system(stage, move |_: &mut ECS, _: &mut EcsCommands, _: f32| log.borrow_mut().push(label));
+    }
+    schedule.run(&mut ecs, DT);
+
+    assert_eq!(
+        *log.borrow(),
+        vec!["pre_update", "update", "update_second", "physics", "post_update", "render"]
+    );
+}
+
+#[test]
+fn test_run_rotates_entity_events() {
+    let mut ecs = ECS::new();
+    let mut schedule = SystemSchedule::new();
+    ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Spawned".to_string()));
+
+    // Events survive the next frame so late systems can read them, then expire
+    schedule.run(&mut ecs, DT);
+    assert_eq!(ecs.events.len(), 1);
+    schedule.run(&mut ecs, DT);
+    assert!(ecs.events.is_empty());
+}
+
+fn schedule_with_lifetimes() -> (SystemSchedule, Rc<RefCell<LifetimeSystem>>) {
+    let mut schedule = SystemSchedule::new();
+    let lifetimes = Rc::new(RefCell::new(LifetimeSystem::new()));
+    let system = Rc::clone(&lifetimes);
+    schedule.add_system(Stage::Update, move |ecs, commands, dt| {
+        system.borrow_mut().update(ecs, dt, commands)
+    });
+    (schedule, lifetimes)
+}
+
+#[test]
+fn test_schedule_drives_lifetimes_and_applies_commands() {
+    let mut ecs = ECS::new();
+    let (mut schedule, lifetimes) = schedule_with_lifetimes();
+    let id = ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Spark".to_string()));
+    lifetimes.borrow_mut().set_lifetime(&ecs, id, 0.04);
+
+    // Seen by a later stage in the same run, once the Update commands apply
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let log = Rc::clone(&seen);
+    schedule.add_system(Stage::PostUpdate, move |ecs: &mut ECS, _: &mut EcsCommands, _: f32| {
+        log.borrow_mut().push(ecs.find_entity_components(id).is_some())
+    });
+
+    for _ in 0..4 {
+        schedule.run(&mut ecs, DT);
+    }
+    assert_eq!(*seen.borrow(), vec![true, true, false, false]);
+}
+
+#[test]
+fn test_recycled_id_survives_after_despawn_event_expires() {
+    let mut ecs = ECS::new();
+    let (mut schedule, lifetimes) = schedule_with_lifetimes();
+    let id = ecs.add_entity(Position { x: 0.0, y: 0.0 }, Name("Pickup".to_string()));
+    lifetimes.borrow_mut().set_lifetime(&ecs, id, 0.1);
+    ecs.remove_entity(id);
+
+    // The Despawned event has expired before the id is reused
+    let mut empty = SystemSchedule::new();
+    empty.run(&mut ecs, DT);
+    empty.run(&mut ecs, DT);
+    let new_id = ecs.add_entity(Position { x: 1.0, y: 1.0 }, Name("Player".to_string()));
+    assert_eq!(new_id, id);
+
+    for _ in 0..10 {
+        schedule.run(&mut ecs, DT);
+    }
+    assert!(ecs.find_entity_components(new_id).is_some());
+}
@@ -111,3 +111,20 @@ fn test_handle_non_existent_tag() {
     assert!(tag_manager.get_entities_with_tag("NonExistent").is_none());
 }
 
+
+#[test]
+fn test_remove_entity_clears_all_its_tags() {
+    let mut tag_manager = TagManager::new();
+
+    tag_manager.add_tag(1, "Player");
+    tag_manager.add_tag(1, "NPC");
+    tag_manager.add_tag(2, "NPC");
+
+    tag_manager.remove_entity(1);
+
+    // Tags only held by the entity are cleaned up, shared ones keep the others
+    assert!(tag_manager.get_entities_with_tag("Player").is_none());
+    let npc_entities = tag_manager.get_entities_with_tag("NPC").unwrap();
+    assert!(!npc_entities.contains(&1));
+    assert!(npc_entities.contains(&2));
+}
@@ -0,0 +1,132 @@
+use rust_game::systems::Timers;
+use rust_game::systems::timers::MIN_INTERVAL;
+
+#[test]
+fn test_one_shot_fires_once() {
+    let mut timers = Timers::new();
+    timers.after(0.5, "explode");
+
+    // Not due yet
+    assert!(timers.update(0.25).is_empty());
+
+    // Fires exactly when the delay has elapsed
+    assert_eq!(timers.update(0.25), vec![("explode".to_string(), 1)]);
+    assert!(!timers.is_scheduled("explode"));
+
+    // Never fires again
+    for _ in 0..10 {
+        assert!(timers.update(0.25).is_empty());
+    }
+}
+
+#[test]
+fn test_repeat_fires_at_cadence() {
+    let mut timers = Timers::new();
+    timers.every(0.5, "tick");
+
+    let fired_frames: Vec<usize> = (1..=8)
+        .filter(|_| !timers.update(0.25).is_empty())
+        .collect();
+
+    assert_eq!(fired_frames, vec![2, 4, 6, 8]);
+    assert!(timers.is_scheduled("tick"));
+}
+
+#[test]
+fn test_repeat_catches_up_on_large_step() {
+    let mut timers = Timers::new();
+    timers.every(0.25, "tick");
+
+    assert_eq!(timers.update(1.0), vec![("tick".to_string(), 4)]);
+}
+
+#[test]
+fn test_fixed_timestep_fire_frames() {
+    let mut timers = Timers::new();
+    timers.every(0.095, "fast");
+    timers.after(0.24, "once");
+
+    let mut fast_frames = Vec::new();
+    let mut once_frames = Vec::new();
+    for frame in 1..=30 {
+        for (key, count) in timers.update(1.0 / 60.0) {
+            assert_eq!(count, 1);
+            match key.as_str() {
+                "fast" => fast_frames.push(frame),
+                "once" => once_frames.push(frame),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    // 0.095s repeats land between frames, so each fires on the next frame after
+    assert_eq!(fast_frames, vec![6, 12, 18, 23, 29]);
+    assert_eq!(once_frames, vec![15]);
+}
+
+#[test]
+fn test_zero_interval_is_clamped() {
+    let mut timers = Timers::new();
+    timers.every(0.0, "spin");
+
+    // Reports a count instead of looping forever or cloning the key per fire
+    let fired = timers.update(5.0);
+    assert_eq!(fired.len(), 1);
+    assert_eq!(fired[0].1, (5.0 / MIN_INTERVAL).round() as u32);
+
+    let fired = timers.update(1.0 / 60.0);
+    assert_eq!(fired.len(), 1);
+    assert!(fired[0].1 <= (1.0 / 60.0 / MIN_INTERVAL).ceil() as u32);
+}
+
+#[test]
+fn test_reschedule_and_cancel() {
+    let mut timers = Timers::new();
+    timers.after(0.25, "door");
+
+    // Re-using a key replaces the pending timer
+    timers.after(1.0, "door");
+    assert!(timers.update(0.5).is_empty());
+
+    timers.cancel("door");
+    assert!(!timers.is_scheduled("door"));
+    assert!(timers.update(1.0).is_empty());
+}
+
+#[test]
+fn test_invalid_dt_is_ignored() {
+    let mut timers = Timers::new();
+    timers.every(0.5, "tick");
+    timers.after(0.5, "once");
+
+    for dt in [f32::INFINITY, f32::NEG_INFINITY, f32::NAN, -1.0] {
+        assert!(timers.update(dt).is_empty());
+    }
+
+    // Timers are unaffected and still fire on schedule
+    assert_eq!(
+        timers.update(0.5),
+        vec![("tick".to_string(), 1), ("once".to_string(), 1)]
+    );
+}
+
+#[test]
+fn test_huge_step_saturates_and_recovers() {
+    let mut timers = Timers::new();
+    timers.every(0.0, "spin");
+
+    let fired = timers.update(1.0e30);
+    assert_eq!(fired, vec![("spin".to_string(), u32::MAX)]);
+
+    // The timer is left with a valid remainder and keeps its normal cadence
+    let fired = timers.update(0.01);
+    assert_eq!(fired.len(), 1);
+    assert!((9..=10).contains(&fired[0].1));
+}
+
+#[test]
+fn test_nan_delay_fires_on_next_update() {
+    let mut timers = Timers::new();
+    timers.after(f32::NAN, "now");
+    assert_eq!(timers.update(0.0), vec![("now".to_string(), 1)]);
+}